@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io;
+use std::path::Path;
+
+use serde::Serialize;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::export::sanitize_filename;
+use crate::{id_in_range, parse_message_id};
+
+#[derive(Serialize)]
+struct AttachmentRecord {
+	message_id: String,
+	stored_path: String,
+	mime_type: String,
+	sha256: String,
+}
+
+fn hash_file(path: &str) -> io::Result<String> {
+	let bytes = fs::read(path)?;
+	Ok(format!("{:x}", Sha256::digest(&bytes)))
+}
+
+pub fn download_attachments(
+	channels: &Vec<(String, String, String, String, usize, usize, bool)>,
+	filter_range: Option<(i64, i64)>,
+) -> io::Result<()> {
+	let output_dir = "exported_attachments";
+	fs::create_dir_all(output_dir)?;
+
+	let mut dedup: HashMap<String, String> = HashMap::new();
+	let mut hashes: HashMap<String, String> = HashMap::new();
+	let mut manifest: Vec<AttachmentRecord> = Vec::new();
+
+	for (_, channel_name, _, channel_id, _, _, selected) in channels {
+		if !selected {
+			continue;
+		}
+
+		let channel_dir = format!("messages/c{}", channel_id);
+		let messages_file_path = format!("{}/messages.json", channel_dir);
+		let messages_content =
+			fs::read_to_string(&messages_file_path).expect("Failed to read messages.json");
+		let messages: Vec<Value> =
+			serde_json::from_str(&messages_content).expect("Failed to parse messages.json");
+
+		let channel_output_dir = format!("{}/{}", output_dir, sanitize_filename(channel_name));
+		fs::create_dir_all(&channel_output_dir)?;
+
+		for msg in &messages {
+			let Some(attachments_field) = msg["Attachments"].as_str() else {
+				continue;
+			};
+			if attachments_field.trim().is_empty() {
+				continue;
+			}
+			let Some(message_id) = parse_message_id(msg) else {
+				continue;
+			};
+			if !id_in_range(message_id, filter_range) {
+				continue;
+			}
+
+			for attachment_path in attachments_field.split_whitespace() {
+				let source_path = format!("{}/{}", channel_dir, attachment_path);
+				if !Path::new(&source_path).exists() {
+					eprintln!("Skipping missing attachment: {}", source_path);
+					continue;
+				}
+
+				// Every source path still needs hashing once, since dedup is by content rather
+				// than path; this only avoids rehashing the same path seen again.
+				let sha256 = if let Some(existing) = hashes.get(&source_path) {
+					existing.clone()
+				} else {
+					let sha256 = hash_file(&source_path)?;
+					hashes.insert(source_path.clone(), sha256.clone());
+					sha256
+				};
+				let mime_type = mime_guess::from_path(attachment_path)
+					.first_or_octet_stream()
+					.to_string();
+
+				let stored_path = if let Some(existing) = dedup.get(&sha256) {
+					existing.clone()
+				} else {
+					let extension = Path::new(attachment_path)
+						.extension()
+						.and_then(|e| e.to_str())
+						.unwrap_or("bin");
+					let dest_path = format!("{}/{}.{}", channel_output_dir, sha256, extension);
+
+					if fs::hard_link(&source_path, &dest_path).is_err() {
+						fs::copy(&source_path, &dest_path)?;
+					}
+
+					dedup.insert(sha256.clone(), dest_path.clone());
+					dest_path
+				};
+
+				manifest.push(AttachmentRecord {
+					message_id: message_id.to_string(),
+					stored_path,
+					mime_type,
+					sha256,
+				});
+			}
+		}
+	}
+
+	let manifest_path = format!("{}/manifest.json", output_dir);
+	let mut manifest_file = File::create(manifest_path)?;
+	serde_json::to_writer_pretty(&mut manifest_file, &manifest)
+		.expect("Failed to write attachment manifest");
+
+	println!(
+		"Downloaded {} attachment(s) into {}/",
+		manifest.len(),
+		output_dir
+	);
+	Ok(())
+}
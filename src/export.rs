@@ -0,0 +1,189 @@
+use std::fs::{self, File};
+use std::io::{self, Write};
+
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct ExportMessage {
+	pub id: String,
+	pub author: String,
+	pub timestamp: String,
+	pub content: String,
+	pub attachments: Vec<String>,
+}
+
+#[derive(Serialize)]
+pub struct ExportChannel {
+	pub id: String,
+	pub name: String,
+	pub channel_type: String,
+	pub creation_date: String,
+	pub messages: Vec<ExportMessage>,
+}
+
+pub trait Exporter {
+	fn export(&self, channels: &[ExportChannel]) -> io::Result<()>;
+}
+
+pub struct TxtExporter;
+
+impl Exporter for TxtExporter {
+	fn export(&self, channels: &[ExportChannel]) -> io::Result<()> {
+		let output_file = "exported_channels.txt";
+		let mut file = File::create(output_file)?;
+
+		for channel in channels {
+			writeln!(file, "# {} ({})", channel.name, channel.channel_type)?;
+			for message in &channel.messages {
+				writeln!(
+					file,
+					"[{}] {}: {}",
+					message.timestamp, message.author, message.content
+				)?;
+				if !message.attachments.is_empty() {
+					writeln!(file, "  attachments: {}", message.attachments.join(", "))?;
+				}
+			}
+			writeln!(file)?;
+		}
+
+		println!(
+			"Conversion completed. The file has been saved as {}",
+			output_file
+		);
+		Ok(())
+	}
+}
+
+pub struct JsonExporter;
+
+impl Exporter for JsonExporter {
+	fn export(&self, channels: &[ExportChannel]) -> io::Result<()> {
+		let output_file = "exported_channels.json";
+		let mut file = File::create(output_file)?;
+		serde_json::to_writer_pretty(&mut file, channels)
+			.expect("Failed to write exported_channels.json");
+
+		println!(
+			"Conversion completed. The file has been saved as {}",
+			output_file
+		);
+		Ok(())
+	}
+}
+
+pub struct CsvExporter;
+
+impl Exporter for CsvExporter {
+	fn export(&self, channels: &[ExportChannel]) -> io::Result<()> {
+		let output_file = "exported_channels.csv";
+		let mut file = File::create(output_file)?;
+		writeln!(
+			file,
+			"channel_id,channel_name,message_id,author,timestamp,content,attachments"
+		)?;
+
+		for channel in channels {
+			for message in &channel.messages {
+				writeln!(
+					file,
+					"{},{},{},{},{},{},{}",
+					csv_field(&channel.id),
+					csv_field(&channel.name),
+					csv_field(&message.id),
+					csv_field(&message.author),
+					csv_field(&message.timestamp),
+					csv_field(&message.content),
+					csv_field(&message.attachments.join("; ")),
+				)?;
+			}
+		}
+
+		println!(
+			"Conversion completed. The file has been saved as {}",
+			output_file
+		);
+		Ok(())
+	}
+}
+
+fn csv_field(value: &str) -> String {
+	if value.contains(',') || value.contains('"') || value.contains('\n') {
+		format!("\"{}\"", value.replace('"', "\"\""))
+	} else {
+		value.to_string()
+	}
+}
+
+pub struct HtmlExporter;
+
+impl Exporter for HtmlExporter {
+	fn export(&self, channels: &[ExportChannel]) -> io::Result<()> {
+		let output_dir = "exported_channels";
+		fs::create_dir_all(output_dir)?;
+
+		for channel in channels {
+			let output_path = format!("{}/{}.html", output_dir, sanitize_filename(&channel.name));
+			let mut file = File::create(&output_path)?;
+
+			writeln!(file, "<!DOCTYPE html>")?;
+			writeln!(
+				file,
+				"<html><head><meta charset=\"utf-8\"><title>{}</title></head><body>",
+				html_escape(&channel.name)
+			)?;
+			writeln!(
+				file,
+				"<h1>{}</h1><p>{} &middot; created {}</p>",
+				html_escape(&channel.name),
+				html_escape(&channel.channel_type),
+				html_escape(&channel.creation_date)
+			)?;
+			writeln!(file, "<ul>")?;
+			for message in &channel.messages {
+				writeln!(
+					file,
+					"<li><strong>{}</strong> <em>{}</em>: {}</li>",
+					html_escape(&message.author),
+					html_escape(&message.timestamp),
+					html_escape(&message.content)
+				)?;
+				for attachment in &message.attachments {
+					writeln!(
+						file,
+						"<div class=\"attachment\"><a href=\"{}\">{}</a></div>",
+						html_escape(attachment),
+						html_escape(attachment)
+					)?;
+				}
+			}
+			writeln!(file, "</ul></body></html>")?;
+		}
+
+		println!(
+			"Conversion completed. Transcripts have been saved under {}/",
+			output_dir
+		);
+		Ok(())
+	}
+}
+
+pub(crate) fn sanitize_filename(name: &str) -> String {
+	name.chars()
+		.map(|c| {
+			if c.is_alphanumeric() || c == '-' || c == '_' {
+				c
+			} else {
+				'_'
+			}
+		})
+		.collect()
+}
+
+fn html_escape(value: &str) -> String {
+	value
+		.replace('&', "&amp;")
+		.replace('<', "&lt;")
+		.replace('>', "&gt;")
+		.replace('"', "&quot;")
+}
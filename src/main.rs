@@ -1,13 +1,18 @@
-use std::collections::BTreeMap;
+mod attachments;
+mod export;
+mod search;
+
+use std::collections::{BTreeMap, HashMap};
 use std::error::Error;
 use std::fs::{self, File};
-use std::io::{self, Write};
+use std::io;
 use std::path::Path;
 use std::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
-use std::sync::Arc;
+use std::sync::{mpsc, Arc};
 use std::thread;
+use std::time::Duration;
 
-use chrono::{TimeZone, Utc};
+use chrono::{Datelike, NaiveDate, TimeZone, Utc};
 use crossterm::event::{self, Event, KeyCode};
 use crossterm::execute;
 use crossterm::terminal::{
@@ -20,10 +25,15 @@ use signal_hook::iterator::Signals;
 use tui::backend::{Backend, CrosstermBackend};
 use tui::layout::Rect;
 use tui::style::{Color, Style};
-use tui::text::{Span, Spans};
+use tui::text::{Span, Spans, Text};
 use tui::widgets::{Block, Borders, List, ListItem, Paragraph};
 use tui::Terminal;
 
+use export::{
+	CsvExporter, ExportChannel, ExportMessage, Exporter, HtmlExporter, JsonExporter, TxtExporter,
+};
+use search::{SearchHit, SearchIndex};
+
 #[derive(Serialize, Deserialize)]
 struct Index {
 	#[serde(flatten)]
@@ -47,11 +57,40 @@ fn update_value(value: &str) -> String {
 	value.to_string()
 }
 
+// Shift off the worker/process/increment bits, then undo Discord's epoch offset.
+pub(crate) fn snowflake_millis(id: u64) -> i64 {
+	((id >> 22) + 1420070400000) as i64
+}
+
+fn snowflake_to_datetime(id: u64) -> chrono::DateTime<Utc> {
+	Utc.timestamp_millis_opt(snowflake_millis(id)).unwrap()
+}
+
 fn parse_snowflake_to_timestamp(snowflake: &str) -> String {
 	let id: u64 = snowflake.parse().expect("Invalid snowflake ID");
-	let timestamp = (id >> 22) + 1420070400000;
-	let dt = Utc.timestamp_millis_opt(timestamp as i64).unwrap();
-	dt.format("%B %Y").to_string()
+	snowflake_to_datetime(id).format("%B %Y").to_string()
+}
+
+pub(crate) fn id_in_range(id: u64, range: Option<(i64, i64)>) -> bool {
+	match range {
+		Some((start, end)) => {
+			let ts = snowflake_millis(id);
+			ts >= start && ts < end
+		}
+		None => true,
+	}
+}
+
+fn snowflake_to_second_timestamp(id: u64) -> String {
+	snowflake_to_datetime(id)
+		.format("%Y-%m-%d %H:%M:%S")
+		.to_string()
+}
+
+pub(crate) fn parse_message_id(msg: &Value) -> Option<u64> {
+	msg["ID"]
+		.as_u64()
+		.or_else(|| msg["ID"].as_str().and_then(|s| s.parse().ok()))
 }
 
 fn preprocess_index(index: &mut Index) {
@@ -63,10 +102,30 @@ fn preprocess_index(index: &mut Index) {
 	serde_json::to_writer_pretty(&mut file, &index).expect("Failed to write updated index.json");
 }
 
-fn load_channels(
-) -> Result<Vec<(String, String, String, String, usize, usize, bool)>, Box<dyn Error>> {
+enum LoadEvent {
+	Total(usize),
+	Channel {
+		channel: (String, String, String, String, usize, usize, bool),
+		contents: Vec<String>,
+	},
+	Done,
+}
+
+fn spawn_channel_loader() -> mpsc::Receiver<LoadEvent> {
+	let (tx, rx) = mpsc::channel();
+
+	thread::spawn(move || {
+		if let Err(err) = load_channels_worker(&tx) {
+			eprintln!("Failed to load channels: {}", err);
+		}
+		let _ = tx.send(LoadEvent::Done);
+	});
+
+	rx
+}
+
+fn load_channels_worker(tx: &mpsc::Sender<LoadEvent>) -> Result<(), Box<dyn Error>> {
 	let messages_dir = "messages";
-	let mut channels_info = Vec::new();
 
 	let index_path = "messages/index.json";
 	let index_file = fs::read_to_string(index_path)?;
@@ -74,6 +133,8 @@ fn load_channels(
 
 	preprocess_index(&mut index);
 
+	let _ = tx.send(LoadEvent::Total(index.channels.len()));
+
 	for (channel_id, channel_name) in &index.channels {
 		let channel_dir = format!("{}/c{}", messages_dir, channel_id);
 		let channel_info_path = format!("{}/channel.json", channel_dir);
@@ -94,6 +155,11 @@ fn load_channels(
 				.filter(|msg| msg["Attachments"] != "")
 				.count();
 
+			let contents: Vec<String> = messages
+				.iter()
+				.map(|msg| msg["Contents"].as_str().unwrap_or("").to_string())
+				.collect();
+
 			if message_count > 0 {
 				let channel_name = if channel_name.starts_with("DM - ") {
 					channel_name.strip_prefix("DM - ").unwrap().to_string()
@@ -101,7 +167,7 @@ fn load_channels(
 					channel_name.clone()
 				};
 
-				channels_info.push((
+				let channel = (
 					channel_info.channel_type.clone(),
 					channel_name,
 					creation_date,
@@ -109,18 +175,213 @@ fn load_channels(
 					message_count,
 					attachment_count,
 					true,
-				));
+				);
+
+				if tx.send(LoadEvent::Channel { channel, contents }).is_err() {
+					return Ok(());
+				}
 			}
 		}
 	}
 
-	channels_info.sort_by(|a, b| {
+	Ok(())
+}
+
+fn sort_channels(channels: &mut Vec<(String, String, String, String, usize, usize, bool)>) {
+	channels.sort_by(|a, b| {
 		b.4.cmp(&a.4)
 			.then_with(|| a.0.cmp(&b.0))
 			.then_with(|| a.1.cmp(&b.1))
 	});
+}
+
+struct FilterState {
+	range: Option<(i64, i64)>,
+	counts: HashMap<String, (usize, usize)>,
+}
+
+impl FilterState {
+	fn new() -> Self {
+		FilterState {
+			range: None,
+			counts: HashMap::new(),
+		}
+	}
+
+	fn is_in_range(&self, channel_id: &str) -> bool {
+		match self.range {
+			Some(_) => self.counts.get(channel_id).map_or(false, |&(n, _)| n > 0),
+			None => true,
+		}
+	}
+
+	fn counts_for(&self, channel: &(String, String, String, String, usize, usize, bool)) -> (usize, usize) {
+		if self.range.is_some() {
+			self.counts.get(&channel.3).copied().unwrap_or((0, 0))
+		} else {
+			(channel.4, channel.5)
+		}
+	}
+}
+
+fn parse_month_start(value: &str) -> Option<i64> {
+	let (year_str, month_str) = value.split_once('-')?;
+	let year: i32 = year_str.parse().ok()?;
+	let month: u32 = month_str.parse().ok()?;
+	let date = NaiveDate::from_ymd_opt(year, month, 1)?;
+	Some(Utc.from_utc_datetime(&date.and_hms_opt(0, 0, 0)?).timestamp_millis())
+}
+
+fn month_after(start_ms: i64) -> Option<i64> {
+	let dt = Utc.timestamp_millis_opt(start_ms).single()?;
+	let next = if dt.month() == 12 {
+		NaiveDate::from_ymd_opt(dt.year() + 1, 1, 1)?
+	} else {
+		NaiveDate::from_ymd_opt(dt.year(), dt.month() + 1, 1)?
+	};
+	Some(Utc.from_utc_datetime(&next.and_hms_opt(0, 0, 0)?).timestamp_millis())
+}
+
+fn parse_month_range(from: &str, to: &str) -> Option<(i64, i64)> {
+	let start = parse_month_start(from)?;
+	let end = month_after(parse_month_start(to)?)?;
+	if end <= start {
+		return None;
+	}
+	Some((start, end))
+}
+
+fn filtered_counts_for_channel(channel_id: &str, range: (i64, i64)) -> Option<(usize, usize)> {
+	let messages_file_path = format!("messages/c{}/messages.json", channel_id);
+	let messages_content = fs::read_to_string(&messages_file_path).ok()?;
+	let messages: Vec<Value> = serde_json::from_str(&messages_content).ok()?;
+
+	let mut message_count = 0;
+	let mut attachment_count = 0;
+	for msg in &messages {
+		let Some(id) = parse_message_id(msg) else {
+			continue;
+		};
+		if id_in_range(id, Some(range)) {
+			message_count += 1;
+			if msg["Attachments"] != "" {
+				attachment_count += 1;
+			}
+		}
+	}
+
+	Some((message_count, attachment_count))
+}
+
+fn recompute_filtered_counts(
+	channels: &[(String, String, String, String, usize, usize, bool)],
+	range: (i64, i64),
+) -> HashMap<String, (usize, usize)> {
+	let mut counts = HashMap::new();
+
+	for channel in channels {
+		let channel_id = &channel.3;
+		if let Some(count) = filtered_counts_for_channel(channel_id, range) {
+			counts.insert(channel_id.clone(), count);
+		}
+	}
+
+	counts
+}
+
+fn apply_filter_command(
+	argument: &str,
+	channels: &[(String, String, String, String, usize, usize, bool)],
+	filter: &mut FilterState,
+) {
+	let mut parts = argument.split_whitespace();
+	let from = parts.next();
+	let to = parts.next();
+
+	match (from, to) {
+		(None, _) => {
+			filter.range = None;
+			filter.counts.clear();
+		}
+		(Some(from), Some(to)) => match parse_month_range(from, to) {
+			Some(range) => {
+				filter.counts = recompute_filtered_counts(channels, range);
+				filter.range = Some(range);
+			}
+			None => println!("Invalid filter range: {} to {}", from, to),
+		},
+		(Some(_), None) => {
+			println!("Usage: filter <from> <to>, e.g. filter 2021-01 2021-03");
+		}
+	}
+}
+
+struct SearchState {
+	mode: bool,
+	input: String,
+	results: Option<Vec<SearchHit>>,
+	query_stems: std::collections::HashSet<String>,
+}
+
+impl SearchState {
+	fn new() -> Self {
+		SearchState {
+			mode: false,
+			input: String::new(),
+			results: None,
+			query_stems: std::collections::HashSet::new(),
+		}
+	}
+
+	fn display_rows<'a>(
+		&'a self,
+		channels: &[(String, String, String, String, usize, usize, bool)],
+	) -> Vec<(usize, Option<&'a SearchHit>)> {
+		match &self.results {
+			Some(hits) => hits
+				.iter()
+				.filter_map(|hit| {
+					channels
+						.iter()
+						.position(|c| c.3 == hit.channel_id)
+						.map(|idx| (idx, Some(hit)))
+				})
+				.collect(),
+			None => (0..channels.len()).map(|idx| (idx, None)).collect(),
+		}
+	}
+}
+
+fn build_snippet_spans(snippet: &str, query_stems: &std::collections::HashSet<String>) -> Spans<'static> {
+	let mut spans = Vec::new();
+	for (i, word) in snippet.split(' ').enumerate() {
+		if i > 0 {
+			spans.push(Span::raw(" "));
+		}
+		let trimmed: String = word.chars().filter(|c| c.is_alphanumeric()).collect();
+		let is_match = search::stem_word(&trimmed).map_or(false, |s| query_stems.contains(&s));
+		if is_match {
+			spans.push(Span::styled(
+				word.to_string(),
+				Style::default().fg(Color::Cyan),
+			));
+		} else {
+			spans.push(Span::raw(word.to_string()));
+		}
+	}
+	Spans::from(spans)
+}
 
-	Ok(channels_info)
+struct LoadProgress {
+	loaded_channels: usize,
+	total_channels: Option<usize>,
+	done: bool,
+}
+
+struct ViewState<'a> {
+	search: &'a SearchState,
+	filter: &'a FilterState,
+	progress: LoadProgress,
 }
 
 fn draw_ui<B: Backend>(
@@ -130,7 +391,16 @@ fn draw_ui<B: Backend>(
 	offset: usize,
 	command_mode: bool,
 	command_input: &str,
+	view: &ViewState,
 ) -> io::Result<()> {
+	let search = view.search;
+	let filter = view.filter;
+	let LoadProgress {
+		loaded_channels,
+		total_channels,
+		done: loading_done,
+	} = view.progress;
+
 	let col_widths = [8, 30, 15, 10, 12];
 	let max_name_length = col_widths[1];
 
@@ -167,56 +437,89 @@ fn draw_ui<B: Backend>(
 			height: size.height - 3,
 		};
 
-		let items: Vec<ListItem> = channels
+		let display_rows = search.display_rows(channels);
+
+		let items: Vec<ListItem> = display_rows
 			.iter()
 			.skip(offset)
 			.take(visible_items)
 			.enumerate()
-			.map(
-				|(
-					i,
-					(_, channel_name, creation_date, _, message_count, attachment_count, selected),
-				)| {
-					let indicator = if *selected { "[x]" } else { "[ ]" };
-
-					let truncated_name = if channel_name.len() > max_name_length {
-						format!("{}...", &channel_name[..max_name_length - 3])
-					} else {
-						channel_name.clone()
-					};
-
-					ListItem::new(format!(
-						"{:<width1$} {:<width2$} {:<width3$} {:>width4$} {:>width5$}",
-						indicator,
-						truncated_name,
-						creation_date,
-						message_count,
-						attachment_count,
-						width1 = col_widths[0],
-						width2 = col_widths[1],
-						width3 = col_widths[2],
-						width4 = col_widths[3],
-						width5 = col_widths[4],
-					))
-					.style(if i + offset == selected_index {
-						Style::default().fg(Color::Yellow)
-					} else {
-						Style::default()
-					})
-				},
-			)
+			.map(|(i, &(channel_index, hit))| {
+				let channel = &channels[channel_index];
+				let (_, channel_name, creation_date, channel_id, _, _, selected) = channel;
+				let indicator = if *selected { "[x]" } else { "[ ]" };
+				let (message_count, attachment_count) = filter.counts_for(channel);
+
+				let truncated_name = if channel_name.len() > max_name_length {
+					format!("{}...", &channel_name[..max_name_length - 3])
+				} else {
+					channel_name.clone()
+				};
+
+				let row = format!(
+					"{:<width1$} {:<width2$} {:<width3$} {:>width4$} {:>width5$}",
+					indicator,
+					truncated_name,
+					creation_date,
+					message_count,
+					attachment_count,
+					width1 = col_widths[0],
+					width2 = col_widths[1],
+					width3 = col_widths[2],
+					width4 = col_widths[3],
+					width5 = col_widths[4],
+				);
+
+				let style = if i + offset == selected_index {
+					Style::default().fg(Color::Yellow)
+				} else if !filter.is_in_range(channel_id) {
+					Style::default().fg(Color::DarkGray)
+				} else {
+					Style::default()
+				};
+
+				let text = match hit {
+					Some(hit) => {
+						let suffix = format!(
+							" ({} hit{})",
+							hit.hit_count,
+							if hit.hit_count == 1 { "" } else { "s" }
+						);
+						let mut lines = vec![Spans::from(format!("{}{}", row, suffix))];
+						if !hit.snippet.is_empty() {
+							lines.push(build_snippet_spans(&hit.snippet, &search.query_stems));
+						}
+						Text::from(lines)
+					}
+					None => Text::from(row),
+				};
+
+				ListItem::new(text).style(style)
+			})
 			.collect();
 
+		let mut title_spans = vec![
+			Span::styled(
+				"Select Channels to Export",
+				Style::default().fg(Color::Magenta),
+			),
+			Span::raw(format!(" ({}/{})", offset + 1, display_rows.len())),
+		];
+		if !loading_done {
+			let progress = match total_channels {
+				Some(total) => format!(" — loaded {} of {} channels", loaded_channels, total),
+				None => format!(" — loaded {} channels", loaded_channels),
+			};
+			title_spans.push(Span::styled(
+				progress,
+				Style::default().fg(Color::DarkGray),
+			));
+		}
+
 		f.render_widget(
 			List::new(items).block(
 				Block::default()
-					.title(Spans::from(vec![
-						Span::styled(
-							"Select Channels to Export",
-							Style::default().fg(Color::Magenta),
-						),
-						Span::raw(format!(" ({}/{})", offset + 1, channels.len())),
-					]))
+					.title(Spans::from(title_spans))
 					.borders(Borders::ALL),
 			),
 			list_area,
@@ -230,64 +533,98 @@ fn draw_ui<B: Backend>(
 				height: 1,
 			};
 			f.render_widget(Paragraph::new(format!(":{}", command_input)), command_area);
+		} else if search.mode {
+			let search_area = Rect {
+				x: size.x,
+				y: size.height - 1,
+				width: size.width,
+				height: 1,
+			};
+			f.render_widget(Paragraph::new(format!("/{}", search.input)), search_area);
 		}
 	})?;
 
 	Ok(())
 }
 
-fn export_to_txt(selected_channels: &Vec<(String, String)>) -> io::Result<()> {
-	let output_file = "exported_channels.txt";
-	let mut channels: BTreeMap<String, Vec<String>> = BTreeMap::new();
+fn resolve_export_channels(
+	channels: &Vec<(String, String, String, String, usize, usize, bool)>,
+	filter_range: Option<(i64, i64)>,
+) -> io::Result<Vec<ExportChannel>> {
+	let mut export_channels = Vec::new();
 
-	for (channel_id, message_id) in selected_channels {
-		channels
-			.entry(channel_id.clone())
-			.or_insert_with(Vec::new)
-			.push(message_id.clone());
-	}
+	for (channel_type, channel_name, creation_date, channel_id, _, _, selected) in channels {
+		if !selected {
+			continue;
+		}
+
+		let messages_file_path = format!("messages/c{}/messages.json", channel_id);
+		let messages_content =
+			fs::read_to_string(&messages_file_path).expect("Failed to read messages.json");
+		let messages: Vec<Value> =
+			serde_json::from_str(&messages_content).expect("Failed to parse messages.json");
+
+		let export_messages = messages
+			.iter()
+			.filter_map(|msg| {
+				let id = parse_message_id(msg)?;
+				if !id_in_range(id, filter_range) {
+					return None;
+				}
+				Some(ExportMessage {
+					id: id.to_string(),
+					author: msg["Author"].as_str().unwrap_or("Unknown").to_string(),
+					timestamp: snowflake_to_second_timestamp(id),
+					content: msg["Contents"].as_str().unwrap_or("").to_string(),
+					attachments: msg["Attachments"]
+						.as_str()
+						.unwrap_or("")
+						.split_whitespace()
+						.map(|s| s.to_string())
+						.collect(),
+				})
+			})
+			.collect();
 
-	let mut txtfile = File::create(output_file)?;
-	for (channel_id, message_ids) in channels {
-		writeln!(txtfile, "{}:", channel_id)?;
-		writeln!(txtfile, "{}", message_ids.join(", "))?;
-		writeln!(txtfile)?;
+		export_channels.push(ExportChannel {
+			id: channel_id.clone(),
+			name: channel_name.clone(),
+			channel_type: channel_type.clone(),
+			creation_date: creation_date.clone(),
+			messages: export_messages,
+		});
 	}
 
-	println!(
-		"Conversion completed. The file has been saved as {}",
-		output_file
-	);
-	Ok(())
+	Ok(export_channels)
 }
 
 fn handle_command(
 	command: &str,
 	channels: &Vec<(String, String, String, String, usize, usize, bool)>,
+	filter_range: Option<(i64, i64)>,
 ) -> io::Result<()> {
-	match command {
+	let mut parts = command.splitn(2, ' ');
+	let name = parts.next().unwrap_or("");
+	let argument = parts.next().unwrap_or("").trim();
+
+	match name {
 		"export" => {
-			let mut selected_channels = Vec::new();
-			for (_, _, _, channel_id, _, _, selected) in channels {
-				if *selected {
-					let messages_file_path = format!("messages/c{}/messages.json", channel_id);
-					let messages_content = fs::read_to_string(&messages_file_path)
-						.expect("Failed to read messages.json");
-					let messages: Vec<Value> = serde_json::from_str(&messages_content)
-						.expect("Failed to parse messages.json");
-
-					for msg in messages {
-						if let Some(msg_id) = msg["ID"]
-							.as_u64()
-							.or_else(|| msg["ID"].as_str().map(|s| s.parse::<u64>().unwrap()))
-						{
-							selected_channels.push((channel_id.clone(), msg_id.to_string()));
-						}
-					}
+			let exporter: Box<dyn Exporter> = match argument {
+				"" | "txt" => Box::new(TxtExporter),
+				"json" => Box::new(JsonExporter),
+				"csv" => Box::new(CsvExporter),
+				"html" => Box::new(HtmlExporter),
+				other => {
+					println!("Unknown export format: {}", other);
+					return Ok(());
 				}
-			}
-			export_to_txt(&selected_channels)?;
-			println!("Exported selected channels to exported_channels.txt");
+			};
+
+			let export_channels = resolve_export_channels(channels, filter_range)?;
+			exporter.export(&export_channels)?;
+		}
+		"download" => {
+			attachments::download_attachments(channels, filter_range)?;
 		}
 		"exit" | "quit" => {
 			disable_raw_mode()?;
@@ -314,7 +651,13 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 		}
 	});
 
-	let mut channels = load_channels().unwrap();
+	let mut channels: Vec<(String, String, String, String, usize, usize, bool)> = Vec::new();
+	let mut search_index = SearchIndex::new();
+	let mut total_channels: Option<usize> = None;
+	let mut loaded_channels = 0;
+	let mut loading_done = false;
+	let loader_rx = spawn_channel_loader();
+
 	let mut selected_index = 0;
 	let mut offset = 0;
 
@@ -329,8 +672,33 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
 	let mut command_mode = false;
 	let mut command_input = String::new();
+	let mut search = SearchState::new();
+	let mut filter = FilterState::new();
 
 	while running.load(AtomicOrdering::SeqCst) {
+		loop {
+			match loader_rx.try_recv() {
+				Ok(LoadEvent::Total(total)) => total_channels = Some(total),
+				Ok(LoadEvent::Channel { channel, contents }) => {
+					search_index.index_channel(&channel.3, &contents);
+					if let Some(range) = filter.range {
+						if let Some(count) = filtered_counts_for_channel(&channel.3, range) {
+							filter.counts.insert(channel.3.clone(), count);
+						}
+					}
+					channels.push(channel);
+					sort_channels(&mut channels);
+					loaded_channels += 1;
+				}
+				Ok(LoadEvent::Done) => loading_done = true,
+				Err(mpsc::TryRecvError::Empty) => break,
+				Err(mpsc::TryRecvError::Disconnected) => {
+					loading_done = true;
+					break;
+				}
+			}
+		}
+
 		draw_ui(
 			&mut terminal,
 			&channels,
@@ -338,8 +706,21 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 			offset,
 			command_mode,
 			&command_input,
+			&ViewState {
+				search: &search,
+				filter: &filter,
+				progress: LoadProgress {
+					loaded_channels,
+					total_channels,
+					done: loading_done,
+				},
+			},
 		)?;
 
+		if !event::poll(Duration::from_millis(100))? {
+			continue;
+		}
+
 		if let Event::Key(key) = event::read()? {
 			if command_mode {
 				match key.code {
@@ -350,7 +731,12 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 						command_input.pop();
 					}
 					KeyCode::Enter => {
-						handle_command(&command_input, &channels)?;
+						if command_input == "filter" || command_input.starts_with("filter ") {
+							let argument = command_input.strip_prefix("filter").unwrap_or("");
+							apply_filter_command(argument.trim(), &channels, &mut filter);
+						} else {
+							handle_command(&command_input, &channels, filter.range)?;
+						}
 						command_mode = false;
 						command_input.clear();
 					}
@@ -360,11 +746,44 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 					}
 					_ => {}
 				}
+			} else if search.mode {
+				match key.code {
+					KeyCode::Char(c) => {
+						search.input.push(c);
+					}
+					KeyCode::Backspace => {
+						search.input.pop();
+					}
+					KeyCode::Enter => {
+						let query = search.input.trim();
+						if query.is_empty() {
+							search.results = None;
+							search.query_stems.clear();
+						} else {
+							search.query_stems =
+								query.split_whitespace().filter_map(search::stem_word).collect();
+							search.results = Some(search_index.search(query));
+						}
+						search.mode = false;
+						search.input.clear();
+						selected_index = 0;
+						offset = 0;
+					}
+					KeyCode::Esc => {
+						search.mode = false;
+						search.input.clear();
+					}
+					_ => {}
+				}
 			} else {
+				let display_len = search.display_rows(&channels).len();
 				match key.code {
 					KeyCode::Char(':') => {
 						command_mode = true;
 					}
+					KeyCode::Char('/') => {
+						search.mode = true;
+					}
 					KeyCode::Up => {
 						if selected_index > 0 {
 							selected_index -= 1;
@@ -374,7 +793,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 						}
 					}
 					KeyCode::Down => {
-						if selected_index < channels.len() - 1 {
+						if display_len > 0 && selected_index < display_len - 1 {
 							selected_index += 1;
 							if selected_index >= offset + (terminal.size()?.height as usize - 3) {
 								offset += 1;
@@ -382,7 +801,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 						}
 					}
 					KeyCode::Char(' ') => {
-						channels[selected_index].6 = !channels[selected_index].6;
+						if let Some(&(channel_index, _)) =
+							search.display_rows(&channels).get(selected_index)
+						{
+							channels[channel_index].6 = !channels[channel_index].6;
+						}
 					}
 					KeyCode::Esc => {
 						break;
@@ -0,0 +1,261 @@
+use std::collections::{HashMap, HashSet};
+
+const STOPWORDS: &[&str] = &[
+	"a", "an", "and", "are", "as", "at", "be", "but", "by", "for", "if", "in", "into", "is", "it",
+	"no", "not", "of", "on", "or", "such", "that", "the", "their", "then", "there", "these",
+	"they", "this", "to", "was", "will", "with", "you", "your", "i", "we", "he", "she", "them",
+	"me", "my", "do", "did", "does",
+];
+
+const SNIPPET_WINDOW: usize = 40;
+
+pub struct Posting {
+	channel_id: String,
+	message_index: usize,
+}
+
+pub struct SearchHit {
+	pub channel_id: String,
+	pub hit_count: usize,
+	pub snippet: String,
+}
+
+#[derive(Default)]
+pub struct SearchIndex {
+	postings: HashMap<String, Vec<Posting>>,
+	messages: HashMap<String, Vec<String>>,
+}
+
+impl SearchIndex {
+	pub fn new() -> Self {
+		SearchIndex::default()
+	}
+
+	pub fn index_channel(&mut self, channel_id: &str, messages: &[String]) {
+		for (message_index, content) in messages.iter().enumerate() {
+			if content.trim().is_empty() {
+				continue;
+			}
+
+			for stem in tokenize(content) {
+				self.postings
+					.entry(stem)
+					.or_insert_with(Vec::new)
+					.push(Posting {
+						channel_id: channel_id.to_string(),
+						message_index,
+					});
+			}
+		}
+
+		self.messages
+			.insert(channel_id.to_string(), messages.to_vec());
+	}
+
+	pub fn search(&self, query: &str) -> Vec<SearchHit> {
+		let query_stems: HashSet<String> = tokenize(query).into_iter().collect();
+		if query_stems.is_empty() {
+			return Vec::new();
+		}
+
+		let mut per_stem_channels: Vec<HashMap<&str, HashSet<usize>>> = Vec::new();
+		for stem in &query_stems {
+			let mut per_channel: HashMap<&str, HashSet<usize>> = HashMap::new();
+			if let Some(postings) = self.postings.get(stem) {
+				for posting in postings {
+					per_channel
+						.entry(posting.channel_id.as_str())
+						.or_insert_with(HashSet::new)
+						.insert(posting.message_index);
+				}
+			}
+			per_stem_channels.push(per_channel);
+		}
+
+		let mut candidate_channels: Option<HashSet<&str>> = None;
+		for per_channel in &per_stem_channels {
+			let keys: HashSet<&str> = per_channel.keys().copied().collect();
+			candidate_channels = Some(match candidate_channels {
+				Some(existing) => existing.intersection(&keys).copied().collect(),
+				None => keys,
+			});
+		}
+
+		let mut hits = Vec::new();
+		for channel_id in candidate_channels.unwrap_or_default() {
+			let mut message_indices: HashSet<usize> = HashSet::new();
+			for per_channel in &per_stem_channels {
+				if let Some(indices) = per_channel.get(channel_id) {
+					message_indices.extend(indices.iter().copied());
+				}
+			}
+
+			let snippet = message_indices
+				.iter()
+				.min()
+				.and_then(|&index| self.messages.get(channel_id).and_then(|m| m.get(index)))
+				.map(|content| build_snippet(content, &query_stems))
+				.unwrap_or_default();
+
+			hits.push(SearchHit {
+				channel_id: channel_id.to_string(),
+				hit_count: message_indices.len(),
+				snippet,
+			});
+		}
+
+		hits.sort_by(|a, b| b.hit_count.cmp(&a.hit_count));
+		hits
+	}
+}
+
+fn tokenize(text: &str) -> Vec<String> {
+	text.split_whitespace().filter_map(stem_word).collect()
+}
+
+pub(crate) fn stem_word(word: &str) -> Option<String> {
+	let normalized: String = word
+		.chars()
+		.filter(|c| c.is_alphanumeric())
+		.flat_map(|c| c.to_lowercase())
+		.collect();
+
+	if normalized.is_empty() || STOPWORDS.contains(&normalized.as_str()) {
+		return None;
+	}
+
+	Some(porter_stem(&normalized))
+}
+
+// Simplified Porter-style stemmer, not a full implementation of the algorithm.
+fn porter_stem(word: &str) -> String {
+	let mut stem = word.to_string();
+
+	if stem.ends_with("sses") || stem.ends_with("ies") {
+		stem.truncate(stem.len() - 2);
+	} else if stem.len() > 3 && stem.ends_with('s') && !stem.ends_with("ss") {
+		stem.pop();
+	}
+
+	if stem.len() > 4 && stem.ends_with("eed") {
+		stem.pop();
+	} else if stem.len() > 5 && stem.ends_with("ing") {
+		stem.truncate(stem.len() - 3);
+	} else if stem.len() > 4 && stem.ends_with("ed") {
+		stem.truncate(stem.len() - 2);
+	}
+
+	if stem.len() > 2 && stem.ends_with('y') {
+		let prev = stem.as_bytes()[stem.len() - 2] as char;
+		if !is_vowel(prev) {
+			stem.pop();
+			stem.push('i');
+		}
+	}
+
+	for (suffix, replacement) in [
+		("ational", "ate"),
+		("tional", "tion"),
+		("iveness", "ive"),
+		("fulness", "ful"),
+		("ousness", "ous"),
+		("ization", "ize"),
+		("ation", "ate"),
+		("ator", "ate"),
+		("alism", "al"),
+		("ment", ""),
+		("ness", ""),
+		("ity", ""),
+		("ly", ""),
+	] {
+		if stem.len() > suffix.len() + 2 && stem.ends_with(suffix) {
+			stem.truncate(stem.len() - suffix.len());
+			stem.push_str(replacement);
+			break;
+		}
+	}
+
+	stem
+}
+
+fn is_vowel(c: char) -> bool {
+	matches!(c, 'a' | 'e' | 'i' | 'o' | 'u')
+}
+
+fn build_snippet(content: &str, query_stems: &HashSet<String>) -> String {
+	let words: Vec<&str> = content.split_whitespace().collect();
+
+	let Some(match_index) = words
+		.iter()
+		.position(|word| stem_word(word).map_or(false, |s| query_stems.contains(&s)))
+	else {
+		return String::new();
+	};
+
+	let half_window = SNIPPET_WINDOW / 2;
+	let start = match_index.saturating_sub(half_window);
+	let end = (match_index + half_window).min(words.len());
+
+	let mut snippet = words[start..end].join(" ");
+	if start > 0 {
+		snippet = format!("...{}", snippet);
+	}
+	if end < words.len() {
+		snippet = format!("{}...", snippet);
+	}
+	snippet
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn porter_stem_strips_plural_s() {
+		assert_eq!(porter_stem("cats"), "cat");
+	}
+
+	#[test]
+	fn porter_stem_skips_plural_rule_for_ss_then_strips_ness() {
+		assert_eq!(porter_stem("happiness"), "happi");
+	}
+
+	#[test]
+	fn porter_stem_strips_ing_suffix() {
+		assert_eq!(porter_stem("running"), "runn");
+	}
+
+	#[test]
+	fn stem_word_drops_stopwords() {
+		assert_eq!(stem_word("the"), None);
+		assert_eq!(stem_word("Cats!"), Some("cat".to_string()));
+	}
+
+	#[test]
+	fn build_snippet_returns_empty_when_no_word_matches() {
+		let query_stems: HashSet<String> = ["xylophone".to_string()].into_iter().collect();
+		assert_eq!(build_snippet("hello world", &query_stems), "");
+	}
+
+	#[test]
+	fn build_snippet_has_no_ellipses_when_content_fits_the_window() {
+		let query_stems: HashSet<String> = ["apple".to_string()].into_iter().collect();
+		assert_eq!(
+			build_snippet("hello apple world", &query_stems),
+			"hello apple world"
+		);
+	}
+
+	#[test]
+	fn build_snippet_truncates_with_ellipses_on_both_sides() {
+		let mut words: Vec<String> = (0..60).map(|i| format!("w{}", i)).collect();
+		words[30] = "apple".to_string();
+		let content = words.join(" ");
+		let query_stems: HashSet<String> = ["apple".to_string()].into_iter().collect();
+
+		let snippet = build_snippet(&content, &query_stems);
+		assert!(snippet.starts_with("..."));
+		assert!(snippet.ends_with("..."));
+		assert!(snippet.contains("apple"));
+	}
+}